@@ -29,12 +29,15 @@
 //! thr.write(b'\n');
 //! ```
 
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![allow(deprecated)]
 #![warn(missing_docs)]
 #![deny(unsafe_op_in_unsafe_fn)]
 
-use ::core::{fmt, marker::PhantomData};
+pub mod reg;
+pub mod vcell;
+
+use ::core::{fmt, iter::FusedIterator, marker::PhantomData};
 
 /// Allow access to a memory location.
 #[derive(Debug)]
@@ -48,17 +51,41 @@ pub enum Warn {}
 #[derive(Debug)]
 pub enum Deny {}
 
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Allow {}
+    impl Sealed for super::Warn {}
+    impl Sealed for super::Deny {}
+}
+
+/// A permission marker ([`Allow`], [`Warn`], or [`Deny`]) that is no more
+/// permissive than `P`.
+///
+/// This trait is sealed and implemented only for [`Allow`], [`Warn`], and
+/// [`Deny`]; it exists so that [`VolBox::restrict`] can be constrained to
+/// never widen access.
+pub trait AtMost<P>: sealed::Sealed {}
+
+impl AtMost<Allow> for Allow {}
+impl AtMost<Allow> for Warn {}
+impl AtMost<Warn> for Warn {}
+
+// `Deny` forbids access outright, so it is no more permissive than any
+// other marker.
+impl<P: sealed::Sealed> AtMost<P> for Deny {}
+
 /// An owned memory location for volatile reads and writes.
 #[repr(transparent)]
 #[derive(Debug)]
 #[must_use]
-pub struct VolBox<T, R, W> {
+pub struct VolBox<T: ?Sized, R, W> {
     loc: *mut T,
     r: PhantomData<R>,
     w: PhantomData<W>,
 }
 
-impl<T, R, W> VolBox<T, R, W> {
+impl<T: ?Sized, R, W> VolBox<T, R, W> {
     /// Acquire ownership of a memory location.
     ///
     /// If either `R` or `W` are [`Warn`], this volatile box should document
@@ -69,12 +96,12 @@ impl<T, R, W> VolBox<T, R, W> {
     /// Behavior is undefined if any of the following conditions are violated
     /// during the lifetime of `self`:
     /// - `loc` must not be aliased by any reference or read/written thru any
-    /// aliased pointer.
+    ///   aliased pointer.
     /// - `loc` must be valid for reads if `R` is not [`Deny`].
     /// - `loc` must be valid for writes if `W` is not [`Deny`].
     /// - `loc` must be properly aligned.
     /// - `loc` must point to a properly initialized value of type `T` if `R`
-    /// is not [`Deny`].
+    ///   is not [`Deny`].
     pub const unsafe fn new(loc: *mut T) -> Self {
         Self {
             loc,
@@ -89,6 +116,59 @@ impl<T, R, W> VolBox<T, R, W> {
     }
 }
 
+impl<T: ?Sized, R: AtMost<R>, W: AtMost<W>> VolBox<T, R, W> {
+    /// Narrows the permissions of this handle to `R2`/`W2`, which must each
+    /// be no more permissive than the current `R`/`W`.
+    ///
+    /// This is the safe, ownership-splitting counterpart to re-running
+    /// [`Self::new`]: it lets a fully-capable handle be downgraded and
+    /// loaned out to a subsystem that should only read, only write, or
+    /// touch neither, without weakening the type-level access model.
+    ///
+    /// # Examples
+    /// Narrowing is allowed:
+    /// ```
+    /// # use mmio::*;
+    /// let mut x = 0u8;
+    /// let full = unsafe { VolBox::<u8, Allow, Allow>::new(&mut x) };
+    /// let read_only: VolBox<u8, Allow, Deny> = full.restrict();
+    /// ```
+    ///
+    /// Widening is rejected at compile time:
+    /// ```compile_fail
+    /// # use mmio::*;
+    /// let mut x = 0u8;
+    /// let read_only = unsafe { VolBox::<u8, Allow, Deny>::new(&mut x) };
+    /// let full: VolBox<u8, Allow, Allow> = read_only.restrict();
+    /// ```
+    pub fn restrict<R2: AtMost<R>, W2: AtMost<W>>(self) -> VolBox<T, R2, W2> {
+        // SAFETY: `R2`/`W2` are no more permissive than `R`/`W`, so every
+        // safety requirement that held for `self` still holds for the
+        // narrowed handle, and `self` is consumed, so there is no new
+        // aliasing.
+        unsafe { VolBox::new(self.into_raw()) }
+    }
+
+    /// Narrows this handle to read-only access, forbidding writes.
+    ///
+    /// # Examples
+    /// ```compile_fail
+    /// # use mmio::*;
+    /// let mut x = 0u8;
+    /// let full = unsafe { VolBox::<u8, Allow, Allow>::new(&mut x) };
+    /// let mut read_only = full.into_read_only();
+    /// read_only.write(0); // `write` does not exist for `VolBox<u8, Allow, Deny>`
+    /// ```
+    pub fn into_read_only(self) -> VolBox<T, R, Deny> {
+        self.restrict()
+    }
+
+    /// Narrows this handle to write-only access, forbidding reads.
+    pub fn into_write_only(self) -> VolBox<T, Deny, W> {
+        self.restrict()
+    }
+}
+
 impl<T: Copy, W> VolBox<T, Warn, W> {
     /// Performs a volatile read on the owned memory location.
     ///
@@ -176,8 +256,48 @@ impl<T: Copy, W, const N: usize> VolBox<[T; N], Allow, W> {
         // initialized value of type `T`, and `T` is `Copy`.
         unsafe { loc.read_volatile() }
     }
+
+    /// Returns an iterator over the volatile values of the owned memory
+    /// locations, in order.
+    ///
+    /// Each element is read with its own volatile read as the iterator is
+    /// advanced; no bulk copy is ever performed, since that would not be
+    /// volatile.
+    pub fn iter(&self) -> Iter<'_, T, W, N> {
+        Iter { vol: self, i: 0 }
+    }
+}
+
+/// An iterator over the volatile values of a [`VolBox<[T; N], Allow, W>`],
+/// returned by [`VolBox::iter`].
+#[must_use]
+pub struct Iter<'a, T, W, const N: usize> {
+    vol: &'a VolBox<[T; N], Allow, W>,
+    i: usize,
+}
+
+impl<'a, T: Copy, W, const N: usize> Iterator for Iter<'a, T, W, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.i == N {
+            return None;
+        }
+        let t = self.vol.read_at(self.i);
+        self.i += 1;
+        Some(t)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rem = N - self.i;
+        (rem, Some(rem))
+    }
 }
 
+impl<'a, T: Copy, W, const N: usize> ExactSizeIterator for Iter<'a, T, W, N> {}
+
+impl<'a, T: Copy, W, const N: usize> FusedIterator for Iter<'a, T, W, N> {}
+
 impl<T: Copy, R, const N: usize> VolBox<[T; N], R, Warn> {
     /// Performs a volatile write on the owned memory location at a specific
     /// index.
@@ -215,16 +335,514 @@ impl<T: Copy, R, const N: usize> VolBox<[T; N], R, Allow> {
         // owned, valid for writes, properly aligned, and `T` is `Copy`.
         unsafe { loc.write_volatile(t) };
     }
+
+    /// Writes each item from `iter` into successive owned memory locations,
+    /// in order.
+    ///
+    /// Stops after at most `N` items; any remaining items in `iter` are
+    /// left undrained.
+    pub fn write_from<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for (i, t) in iter.into_iter().take(N).enumerate() {
+            self.write_at(i, t);
+        }
+    }
+}
+
+impl<T: Copy> VolBox<T, Allow, Allow> {
+    /// Performs a volatile read-modify-write on the owned memory location:
+    /// reads the current value, applies `f` to a stack copy, then writes
+    /// the result back.
+    pub fn apply<F: FnOnce(&mut T)>(&mut self, f: F) {
+        let mut t = self.read();
+        f(&mut t);
+        self.write(t);
+    }
+}
+
+impl<T: Copy> VolBox<T, Warn, Allow> {
+    /// Performs a volatile read-modify-write on the owned memory location:
+    /// reads the current value, applies `f` to a stack copy, then writes
+    /// the result back.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    pub unsafe fn apply<F: FnOnce(&mut T)>(&mut self, f: F) {
+        // SAFETY: the safety requirements of `read` are upheld by the
+        // caller.
+        let mut t = unsafe { self.read() };
+        f(&mut t);
+        self.write(t);
+    }
+}
+
+impl<T: Copy> VolBox<T, Allow, Warn> {
+    /// Performs a volatile read-modify-write on the owned memory location:
+    /// reads the current value, applies `f` to a stack copy, then writes
+    /// the result back.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    pub unsafe fn apply<F: FnOnce(&mut T)>(&mut self, f: F) {
+        let mut t = self.read();
+        f(&mut t);
+        // SAFETY: the safety requirements of `write` are upheld by the
+        // caller.
+        unsafe { self.write(t) };
+    }
+}
+
+impl<T: Copy> VolBox<T, Warn, Warn> {
+    /// Performs a volatile read-modify-write on the owned memory location:
+    /// reads the current value, applies `f` to a stack copy, then writes
+    /// the result back.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    pub unsafe fn apply<F: FnOnce(&mut T)>(&mut self, f: F) {
+        // SAFETY: the safety requirements of `read` are upheld by the
+        // caller.
+        let mut t = unsafe { self.read() };
+        f(&mut t);
+        // SAFETY: the safety requirements of `write` are upheld by the
+        // caller.
+        unsafe { self.write(t) };
+    }
+}
+
+impl<T: Copy, const N: usize> VolBox<[T; N], Allow, Allow> {
+    /// Performs a volatile read-modify-write on the owned memory location
+    /// at a specific index: reads the current value, applies `f` to a
+    /// stack copy, then writes the result back.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    pub fn apply_at<F: FnOnce(&mut T)>(&mut self, i: usize, f: F) {
+        let mut t = self.read_at(i);
+        f(&mut t);
+        self.write_at(i, t);
+    }
+}
+
+impl<T: Copy, const N: usize> VolBox<[T; N], Warn, Allow> {
+    /// Performs a volatile read-modify-write on the owned memory location
+    /// at a specific index: reads the current value, applies `f` to a
+    /// stack copy, then writes the result back.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    pub unsafe fn apply_at<F: FnOnce(&mut T)>(&mut self, i: usize, f: F) {
+        // SAFETY: the safety requirements of `read_at` are upheld by the
+        // caller.
+        let mut t = unsafe { self.read_at(i) };
+        f(&mut t);
+        self.write_at(i, t);
+    }
+}
+
+impl<T: Copy, const N: usize> VolBox<[T; N], Allow, Warn> {
+    /// Performs a volatile read-modify-write on the owned memory location
+    /// at a specific index: reads the current value, applies `f` to a
+    /// stack copy, then writes the result back.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    pub unsafe fn apply_at<F: FnOnce(&mut T)>(&mut self, i: usize, f: F) {
+        let mut t = self.read_at(i);
+        f(&mut t);
+        // SAFETY: the safety requirements of `write_at` are upheld by the
+        // caller.
+        unsafe { self.write_at(i, t) };
+    }
+}
+
+impl<T: Copy, const N: usize> VolBox<[T; N], Warn, Warn> {
+    /// Performs a volatile read-modify-write on the owned memory location
+    /// at a specific index: reads the current value, applies `f` to a
+    /// stack copy, then writes the result back.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    pub unsafe fn apply_at<F: FnOnce(&mut T)>(&mut self, i: usize, f: F) {
+        // SAFETY: the safety requirements of `read_at` are upheld by the
+        // caller.
+        let mut t = unsafe { self.read_at(i) };
+        f(&mut t);
+        // SAFETY: the safety requirements of `write_at` are upheld by the
+        // caller.
+        unsafe { self.write_at(i, t) };
+    }
+}
+
+impl<T: Copy, W> VolBox<[T], Warn, W> {
+    /// Performs a volatile read on the owned memory location at a specific
+    /// index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    #[must_use]
+    pub unsafe fn read_at(&self, i: usize) -> T {
+        assert!(i < self.loc.len());
+        let loc = self.loc as *mut T;
+        // SAFETY: `add` is safe because the index is within bounds of the
+        // slice.
+        let loc = unsafe { loc.add(i) };
+        // SAFETY: `read_volatile` is safe because the memory location is
+        // owned, valid for reads, properly aligned, points to a properly
+        // initialized value of type `T`, and `T` is `Copy`.
+        unsafe { loc.read_volatile() }
+    }
+}
+
+impl<T: Copy, W> VolBox<[T], Allow, W> {
+    /// Performs a volatile read on the owned memory location at a specific
+    /// index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    #[must_use]
+    pub fn read_at(&self, i: usize) -> T {
+        assert!(i < self.loc.len());
+        let loc = self.loc as *mut T;
+        // SAFETY: `add` is safe because the index is within bounds of the
+        // slice.
+        let loc = unsafe { loc.add(i) };
+        // SAFETY: `read_volatile` is safe because the memory location is
+        // owned, valid for reads, properly aligned, points to a properly
+        // initialized value of type `T`, and `T` is `Copy`.
+        unsafe { loc.read_volatile() }
+    }
+}
+
+impl<T: Copy, R> VolBox<[T], R, Warn> {
+    /// Performs a volatile write on the owned memory location at a specific
+    /// index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    pub unsafe fn write_at(&mut self, i: usize, t: T) {
+        assert!(i < self.loc.len());
+        let loc = self.loc as *mut T;
+        // SAFETY: `add` is safe because the index is within bounds of the
+        // slice.
+        let loc = unsafe { loc.add(i) };
+        // SAFETY: `write_volatile` is safe because the memory location is
+        // owned, valid for writes, properly aligned, and `T` is `Copy`.
+        unsafe { loc.write_volatile(t) };
+    }
+}
+
+impl<T: Copy, R> VolBox<[T], R, Allow> {
+    /// Performs a volatile write on the owned memory location at a specific
+    /// index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    pub fn write_at(&mut self, i: usize, t: T) {
+        assert!(i < self.loc.len());
+        let loc = self.loc as *mut T;
+        // SAFETY: `add` is safe because the index is within bounds of the
+        // slice.
+        let loc = unsafe { loc.add(i) };
+        // SAFETY: `write_volatile` is safe because the memory location is
+        // owned, valid for writes, properly aligned, and `T` is `Copy`.
+        unsafe { loc.write_volatile(t) };
+    }
+}
+
+/// A pair of independently-owned sub-slice windows, returned by
+/// [`VolBox::split_at`].
+type SplitAt<T, R, W> = (VolBox<[T], R, W>, VolBox<[T], R, W>);
+
+impl<T, R, W, const N: usize> VolBox<[T; N], R, W> {
+    /// Splits the owned array into two independently-owned, dynamically-
+    /// sized sub-windows: the first covers indices `0..m`, the second
+    /// covers `m..N`.
+    ///
+    /// This consumes `self`, so the two halves can be handed to different
+    /// owners while upholding the non-aliasing safety requirement on
+    /// [`Self::new`].
+    ///
+    /// # Panics
+    /// Panics if `m > N`.
+    pub fn split_at(self, m: usize) -> SplitAt<T, R, W> {
+        assert!(m <= N);
+        let loc = self.into_raw() as *mut T;
+        // SAFETY: `m <= N`, so the first `m` elements and the remaining
+        // `N - m` elements are each within bounds of the original array,
+        // and `self` is consumed, so the two halves do not alias each
+        // other or any other owned handle.
+        let first = unsafe { VolBox::new(::core::ptr::slice_from_raw_parts_mut(loc, m)) };
+        // SAFETY: as above.
+        let second =
+            unsafe { VolBox::new(::core::ptr::slice_from_raw_parts_mut(loc.add(m), N - m)) };
+        (first, second)
+    }
 }
 
-impl<T, R, W> fmt::Pointer for VolBox<T, R, W> {
+impl<T: ?Sized, R, W> fmt::Pointer for VolBox<T, R, W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.loc.fmt(f)
     }
 }
 
 // SAFETY: `Send` is safe because `T` is `Send`.
-unsafe impl<T: Send, R, W> Send for VolBox<T, R, W> {}
+unsafe impl<T: Send + ?Sized, R, W> Send for VolBox<T, R, W> {}
+
+// SAFETY: `Sync` is safe because `T` is `Sync`.
+unsafe impl<T: Sync + ?Sized, R, W> Sync for VolBox<T, R, W> {}
+
+/// An owned memory location for `N` identically-shaped register blocks,
+/// each `STRIDE` bytes apart, starting at a base address.
+///
+/// Unlike [`VolBox<[T; N], R, W>`], which assumes the `N` elements are
+/// packed contiguously (`loc.add(i)` steps by `size_of::<T>()`), `VolSeries`
+/// computes the address of element `i` as `base + i * STRIDE`. This models
+/// register banks whose elements are separated by padding, such as a DMA
+/// channel array where each channel exposes a single control register at a
+/// fixed offset within a larger, evenly spaced block.
+#[repr(transparent)]
+#[derive(Debug)]
+#[must_use]
+pub struct VolSeries<T, R, W, const N: usize, const STRIDE: usize> {
+    base: *mut T,
+    r: PhantomData<R>,
+    w: PhantomData<W>,
+}
+
+impl<T, R, W, const N: usize, const STRIDE: usize> VolSeries<T, R, W, N, STRIDE> {
+    /// Acquire ownership of `N` strided memory locations starting at `base`.
+    ///
+    /// If either `R` or `W` are [`Warn`], this volatile series should
+    /// document the additional safety requirements for
+    /// [`Self::read_at`]/[`Self::write_at`] respectively.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are
+    /// violated during the lifetime of `self`, for every `i` in `0..N`,
+    /// where `loc_i` is `base` offset by `i * STRIDE` bytes:
+    /// - `loc_i` must not be aliased by any reference or read/written thru
+    ///   any aliased pointer.
+    /// - `loc_i` must be valid for reads if `R` is not [`Deny`].
+    /// - `loc_i` must be valid for writes if `W` is not [`Deny`].
+    /// - `loc_i` must be properly aligned.
+    /// - `loc_i` must point to a properly initialized value of type `T` if
+    ///   `R` is not [`Deny`].
+    pub const unsafe fn new(base: *mut T) -> Self {
+        Self {
+            base,
+            r: PhantomData,
+            w: PhantomData,
+        }
+    }
+
+    /// Release ownership of the base memory location.
+    pub fn into_raw(self) -> *mut T {
+        self.base
+    }
+}
+
+impl<T: Copy, W, const N: usize, const STRIDE: usize> VolSeries<T, Warn, W, N, STRIDE> {
+    /// Performs a volatile read on the owned memory location at a specific
+    /// index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    #[must_use]
+    pub unsafe fn read_at(&self, i: usize) -> T {
+        assert!(i < N);
+        let loc = self.base as *mut u8;
+        // SAFETY: `add` is safe because the index is within bounds of the
+        // series and each slot is `STRIDE` bytes apart.
+        let loc = unsafe { loc.add(i * STRIDE) as *mut T };
+        // SAFETY: `read_volatile` is safe because the memory location is
+        // owned, valid for reads, properly aligned, points to a properly
+        // initialized value of type `T`, and `T` is `Copy`.
+        unsafe { loc.read_volatile() }
+    }
+}
+
+impl<T: Copy, W, const N: usize, const STRIDE: usize> VolSeries<T, Allow, W, N, STRIDE> {
+    /// Performs a volatile read on the owned memory location at a specific
+    /// index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    #[must_use]
+    pub fn read_at(&self, i: usize) -> T {
+        assert!(i < N);
+        let loc = self.base as *mut u8;
+        // SAFETY: `add` is safe because the index is within bounds of the
+        // series and each slot is `STRIDE` bytes apart.
+        let loc = unsafe { loc.add(i * STRIDE) as *mut T };
+        // SAFETY: `read_volatile` is safe because the memory location is
+        // owned, valid for reads, properly aligned, points to a properly
+        // initialized value of type `T`, and `T` is `Copy`.
+        unsafe { loc.read_volatile() }
+    }
+}
+
+impl<T: Copy, R, const N: usize, const STRIDE: usize> VolSeries<T, R, Warn, N, STRIDE> {
+    /// Performs a volatile write on the owned memory location at a specific
+    /// index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    /// Please consult the documentation on `self`.
+    pub unsafe fn write_at(&mut self, i: usize, t: T) {
+        assert!(i < N);
+        let loc = self.base as *mut u8;
+        // SAFETY: `add` is safe because the index is within bounds of the
+        // series and each slot is `STRIDE` bytes apart.
+        let loc = unsafe { loc.add(i * STRIDE) as *mut T };
+        // SAFETY: `write_volatile` is safe because the memory location is
+        // owned, valid for writes, properly aligned, and `T` is `Copy`.
+        unsafe { loc.write_volatile(t) };
+    }
+}
+
+impl<T: Copy, R, const N: usize, const STRIDE: usize> VolSeries<T, R, Allow, N, STRIDE> {
+    /// Performs a volatile write on the owned memory location at a specific
+    /// index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    pub fn write_at(&mut self, i: usize, t: T) {
+        assert!(i < N);
+        let loc = self.base as *mut u8;
+        // SAFETY: `add` is safe because the index is within bounds of the
+        // series and each slot is `STRIDE` bytes apart.
+        let loc = unsafe { loc.add(i * STRIDE) as *mut T };
+        // SAFETY: `write_volatile` is safe because the memory location is
+        // owned, valid for writes, properly aligned, and `T` is `Copy`.
+        unsafe { loc.write_volatile(t) };
+    }
+}
+
+impl<T, R, W, const N: usize, const STRIDE: usize> fmt::Pointer for VolSeries<T, R, W, N, STRIDE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.base.fmt(f)
+    }
+}
+
+// SAFETY: `Send` is safe because `T` is `Send`.
+unsafe impl<T: Send, R, W, const N: usize, const STRIDE: usize> Send
+    for VolSeries<T, R, W, N, STRIDE>
+{
+}
 
 // SAFETY: `Sync` is safe because `T` is `Sync`.
-unsafe impl<T: Sync, R, W> Sync for VolBox<T, R, W> {}
+unsafe impl<T: Sync, R, W, const N: usize, const STRIDE: usize> Sync
+    for VolSeries<T, R, W, N, STRIDE>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A padded register block, mirroring the DMA-channel motivation for
+    // `VolSeries`: each block is larger than the single `u32` register it
+    // exposes, so a stride/size mix-up would corrupt a neighboring block.
+    #[repr(C)]
+    struct ChannelBlock {
+        ctrl: u32,
+        _pad: [u8; 0x1c],
+    }
+
+    #[test]
+    fn vol_series_write_at_does_not_disturb_neighbors() {
+        const STRIDE: usize = ::core::mem::size_of::<ChannelBlock>();
+        let mut channels = [0u32; STRIDE * 3 / ::core::mem::size_of::<u32>()];
+        let base = channels.as_mut_ptr();
+        let mut series = unsafe { VolSeries::<u32, Allow, Allow, 3, STRIDE>::new(base) };
+
+        series.write_at(1, 0xdead_beef);
+
+        assert_eq!(series.read_at(0), 0);
+        assert_eq!(series.read_at(1), 0xdead_beef);
+        assert_eq!(series.read_at(2), 0);
+    }
+
+    #[test]
+    fn restrict_narrows_to_read_only() {
+        let mut x = 5u8;
+        let full = unsafe { VolBox::<u8, Allow, Allow>::new(&mut x) };
+        let read_only: VolBox<u8, Allow, Deny> = full.restrict();
+        assert_eq!(read_only.read(), 5);
+    }
+
+    #[test]
+    fn into_read_only_forbids_writes_and_preserves_reads() {
+        let mut x = 7u8;
+        let full = unsafe { VolBox::<u8, Allow, Allow>::new(&mut x) };
+        let read_only = full.into_read_only();
+        assert_eq!(read_only.read(), 7);
+    }
+
+    #[test]
+    fn into_write_only_forbids_reads_and_preserves_writes() {
+        let mut x = 0u8;
+        let full = unsafe { VolBox::<u8, Allow, Allow>::new(&mut x) };
+        let mut write_only = full.into_write_only();
+        write_only.write(9);
+        assert_eq!(x, 9);
+    }
+
+    #[test]
+    fn split_at_partitions_array_without_aliasing() {
+        let mut arr = [1u8, 2, 3, 4];
+        let vol = unsafe { VolBox::<[u8; 4], Allow, Allow>::new(&mut arr as *mut [u8; 4]) };
+        let (mut first, mut second) = vol.split_at(2);
+
+        assert_eq!(first.read_at(0), 1);
+        assert_eq!(first.read_at(1), 2);
+        assert_eq!(second.read_at(0), 3);
+        assert_eq!(second.read_at(1), 4);
+
+        first.write_at(0, 10);
+        second.write_at(0, 30);
+        assert_eq!(arr, [10, 2, 30, 4]);
+    }
+
+    #[test]
+    fn split_at_zero_yields_an_empty_first_half() {
+        let mut arr = [1u8, 2, 3];
+        let vol = unsafe { VolBox::<[u8; 3], Allow, Allow>::new(&mut arr as *mut [u8; 3]) };
+        let (_first, second) = vol.split_at(0);
+
+        assert_eq!(second.read_at(0), 1);
+        assert_eq!(second.read_at(1), 2);
+        assert_eq!(second.read_at(2), 3);
+    }
+
+    #[test]
+    fn split_at_n_yields_an_empty_second_half() {
+        let mut arr = [1u8, 2, 3];
+        let vol = unsafe { VolBox::<[u8; 3], Allow, Allow>::new(&mut arr as *mut [u8; 3]) };
+        let (first, _second) = vol.split_at(3);
+
+        assert_eq!(first.read_at(0), 1);
+        assert_eq!(first.read_at(1), 2);
+        assert_eq!(first.read_at(2), 3);
+    }
+}
@@ -1,6 +1,9 @@
+//! A volatile memory cell suitable for MMIO.
+
 use ::core::{
     cell::UnsafeCell,
     ptr::{read_volatile, write_volatile},
+    slice,
 };
 
 /// A mutable, volatile memory location suitable for MMIO.
@@ -32,10 +35,26 @@ impl<T> VCell<T> {
         // Safety: `address` is suitably aligned, is not dangling during `'a`,
         // and points to a valid value because of the safety requirements on
         // the caller.
-        &*(address as *mut VCell<T>)
+        unsafe { &*(address as *mut VCell<T>) }
     }
 
-    // TODO: Add a `conjure_many` that returns `&'a [VCell<T>]`.
+    /// Conjures up a slice of `len` contiguous `VCell<T>`s with lifetime
+    /// `'a` starting at `address`.
+    ///
+    /// # Safety
+    /// Behavior is undefined if any of the following conditions are
+    /// violated during `'a`, for every `i` in `0..len`:
+    /// - `address.add(i)` must be valid for reads and writes.
+    /// - `address.add(i)` must be properly aligned.
+    /// - `address.add(i)` must point to a properly initialized value of
+    ///   type `T`.
+    pub unsafe fn conjure_many<'a>(address: *mut T, len: usize) -> &'a [VCell<T>] {
+        // Safety: `address` is suitably aligned, is not dangling during
+        // `'a`, and points to `len` valid, contiguous values because of the
+        // safety requirements on the caller. `VCell<T>` is
+        // `#[repr(transparent)]` over `T`, so the cast preserves layout.
+        unsafe { slice::from_raw_parts(address as *const VCell<T>, len) }
+    }
 }
 
 impl<T: Copy> VCell<T> {